@@ -0,0 +1,65 @@
+use pulldown_cmark::{html, Event, HeadingLevel, Options, Parser, Tag, TagEnd};
+
+// The page already owns `h1` (site name) and `h2` (post title), so a post's
+// `#` heading renders as `h3`.
+const HEADING_OFFSET: usize = 2;
+
+pub fn is_valid_slug(slug: &str) -> bool {
+    !slug.is_empty()
+        && slug
+            .bytes()
+            .all(|byte| byte.is_ascii_lowercase() || byte.is_ascii_digit() || byte == b'-')
+}
+
+pub fn post_markdown_url(slug: &str) -> String {
+    format!("/posts/{slug}.md")
+}
+
+fn shifted_heading(level: HeadingLevel) -> HeadingLevel {
+    HeadingLevel::try_from((level as usize + HEADING_OFFSET).min(6)).unwrap_or(HeadingLevel::H6)
+}
+
+pub fn render_markdown(source: &str) -> String {
+    let options = Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH | Options::ENABLE_FOOTNOTES;
+    let events = Parser::new_ext(source, options).map(|event| match event {
+        Event::Start(Tag::Heading {
+            level,
+            id,
+            classes,
+            attrs,
+        }) => Event::Start(Tag::Heading {
+            level: shifted_heading(level),
+            id,
+            classes,
+            attrs,
+        }),
+        Event::End(TagEnd::Heading(level)) => Event::End(TagEnd::Heading(shifted_heading(level))),
+        other => other,
+    });
+
+    let mut rendered = String::with_capacity(source.len() * 3 / 2);
+    html::push_html(&mut rendered, events);
+    rendered
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slugs_are_lowercase_kebab() {
+        assert!(is_valid_slug("rebuilding-in-yew-2026"));
+        assert!(!is_valid_slug(""));
+        assert!(!is_valid_slug("../index"));
+        assert!(!is_valid_slug("Mixed-Case"));
+        assert!(!is_valid_slug("with space"));
+    }
+
+    #[test]
+    fn headings_render_below_the_post_title() {
+        let rendered = render_markdown("# Intro\n\n#### Deep\n\nbody");
+        assert!(rendered.contains("<h3>Intro</h3>"));
+        assert!(rendered.contains("<h6>Deep</h6>"));
+        assert!(rendered.contains("<p>body</p>"));
+    }
+}
@@ -0,0 +1,151 @@
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SimpleDate {
+    pub year: i32,
+    pub month: u32,
+    pub day: u32,
+}
+
+pub fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || (year % 400 == 0)
+}
+
+pub fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        _ => 30,
+    }
+}
+
+fn ascii_number(digits: &[u8]) -> Option<u32> {
+    digits.iter().try_fold(0u32, |value, &byte| {
+        byte.is_ascii_digit().then(|| value * 10 + u32::from(byte - b'0'))
+    })
+}
+
+/// Parses a strict ISO `YYYY-MM-DD` date, rejecting other widths and days
+/// that do not exist in the given month.
+pub fn parse_iso_date(text: &str) -> Option<SimpleDate> {
+    let bytes = text.as_bytes();
+    if bytes.len() != 10 || bytes[4] != b'-' || bytes[7] != b'-' {
+        return None;
+    }
+
+    let year = i32::try_from(ascii_number(&bytes[0..4])?).ok()?;
+    let month = ascii_number(&bytes[5..7])?;
+    let day = ascii_number(&bytes[8..10])?;
+    if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) {
+        return None;
+    }
+
+    Some(SimpleDate { year, month, day })
+}
+
+// Days since 1970-01-01 in the proleptic Gregorian calendar, using eras of
+// 400 years so the result is exact for negative years too.
+fn days_from_civil(date: SimpleDate) -> i64 {
+    let month = i64::from(date.month);
+    let day = i64::from(date.day);
+    let year = i64::from(date.year) - i64::from(month <= 2);
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let month_from_march = (month + 9) % 12;
+    let day_of_year = (153 * month_from_march + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Whole days from `start` to `end`, or `None` when `end` is earlier or the
+/// span does not fit in a `u32`. Both dates are assumed to be valid.
+pub fn day_offset(start: SimpleDate, end: SimpleDate) -> Option<u32> {
+    if end < start {
+        return None;
+    }
+
+    u32::try_from(days_from_civil(end) - days_from_civil(start)).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Datelike, Duration, NaiveDate};
+    use proptest::prelude::*;
+
+    fn epoch() -> NaiveDate {
+        NaiveDate::from_ymd_opt(1970, 1, 1).unwrap()
+    }
+
+    fn from_chrono(date: NaiveDate) -> SimpleDate {
+        SimpleDate {
+            year: date.year(),
+            month: date.month(),
+            day: date.day(),
+        }
+    }
+
+    fn any_date() -> impl Strategy<Value = NaiveDate> {
+        (-1_000_000i64..1_000_000).prop_map(|days| epoch() + Duration::days(days))
+    }
+
+    #[test]
+    fn epoch_is_day_zero() {
+        assert_eq!(days_from_civil(from_chrono(epoch())), 0);
+    }
+
+    #[test]
+    fn energy_start_offsets_match_calendar() {
+        let start = SimpleDate { year: 2026, month: 1, day: 12 };
+        let leap_day = SimpleDate { year: 2028, month: 2, day: 29 };
+        assert_eq!(day_offset(start, start), Some(0));
+        assert_eq!(day_offset(start, SimpleDate { year: 2026, month: 2, day: 1 }), Some(20));
+        assert_eq!(day_offset(start, leap_day), Some(778));
+        assert_eq!(day_offset(leap_day, start), None);
+    }
+
+    #[test]
+    fn iso_dates_must_be_zero_padded_and_real() {
+        assert_eq!(parse_iso_date("2026-03-01"), Some(SimpleDate { year: 2026, month: 3, day: 1 }));
+        assert_eq!(parse_iso_date("2028-02-29"), Some(SimpleDate { year: 2028, month: 2, day: 29 }));
+        assert_eq!(parse_iso_date("2026-3-1"), None);
+        assert_eq!(parse_iso_date("March 1"), None);
+        assert_eq!(parse_iso_date("2026-02-29"), None);
+        assert_eq!(parse_iso_date("2026-13-01"), None);
+        assert_eq!(parse_iso_date("2026-+1-01"), None);
+    }
+
+    proptest! {
+        #[test]
+        fn parse_iso_date_round_trips_chrono(days in 0i64..2_000_000) {
+            let date = NaiveDate::from_ymd_opt(1, 1, 1).unwrap() + Duration::days(days);
+            let text = date.format("%Y-%m-%d").to_string();
+            prop_assert_eq!(parse_iso_date(&text), Some(from_chrono(date)));
+        }
+
+
+        #[test]
+        fn days_from_civil_matches_chrono(date in any_date()) {
+            let expected = date.signed_duration_since(epoch()).num_days();
+            prop_assert_eq!(days_from_civil(from_chrono(date)), expected);
+        }
+
+        #[test]
+        fn day_offset_matches_chrono(start in any_date(), end in any_date()) {
+            let expected = u32::try_from(end.signed_duration_since(start).num_days()).ok();
+            prop_assert_eq!(day_offset(from_chrono(start), from_chrono(end)), expected);
+        }
+
+        #[test]
+        fn days_in_month_matches_chrono(year in -5_000i32..5_000, month in 1u32..=12) {
+            let first = NaiveDate::from_ymd_opt(year, month, 1).unwrap();
+            let next_first = if month == 12 {
+                NaiveDate::from_ymd_opt(year + 1, 1, 1).unwrap()
+            } else {
+                NaiveDate::from_ymd_opt(year, month + 1, 1).unwrap()
+            };
+            let expected = next_first.signed_duration_since(first).num_days();
+            prop_assert_eq!(i64::from(days_in_month(year, month)), expected);
+        }
+    }
+}
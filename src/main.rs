@@ -8,16 +8,38 @@ fn main() {
     frontend::run();
 }
 
+#[cfg_attr(not(target_arch = "wasm32"), allow(dead_code))]
+mod blog;
+
+#[cfg_attr(not(target_arch = "wasm32"), allow(dead_code))]
+mod dates;
+
+#[cfg_attr(not(target_arch = "wasm32"), allow(dead_code))]
+mod projects;
+
+#[cfg(target_arch = "wasm32")]
+mod routes;
+
 #[cfg(target_arch = "wasm32")]
 mod frontend {
-    use std::{cell::RefCell, collections::HashSet, rc::Rc};
+    use std::{
+        cell::RefCell,
+        collections::{HashSet, VecDeque},
+        rc::Rc,
+    };
 
     use gloo_timers::callback::Timeout;
     use js_sys::{Array, ArrayBuffer, Date, Function, JSON, Object, Reflect, WebAssembly};
     use wasm_bindgen::{closure::Closure, JsCast};
     use wasm_bindgen_futures::{spawn_local, JsFuture};
-    use web_sys::{window, FocusEvent, HtmlElement, HtmlImageElement, MouseEvent, Request, RequestInit, RequestMode, Response, Storage};
+    use web_sys::{window, FocusEvent, HtmlElement, HtmlImageElement, KeyboardEvent, MouseEvent, Request, RequestInit, RequestMode, Response, Storage};
     use yew::prelude::*;
+    use yew_router::prelude::*;
+
+    use crate::blog::{is_valid_slug, post_markdown_url, render_markdown};
+    use crate::dates::{day_offset, days_in_month, parse_iso_date, SimpleDate};
+    use crate::projects::{projects, Project};
+    use crate::routes::{Route, PRIMARY_NAV};
 
     const THEME_KEY: &str = "portfolio-theme";
     const PREVIEW_GUTTER: f64 = 14.0;
@@ -32,11 +54,17 @@ mod frontend {
     const PREVIEW_LOADING_ALT: &str = "Preview loading";
     const GITHUB_LINK_SCREENSHOT: &str = "/previews/manual/github.png";
     const METRIC_ROTATION_MS: i32 = 3200;
+    const HEAP_METRIC_LABEL: &str = "wasm heap size";
+    const HEAP_SAMPLE_INTERVAL_MS: i32 = 1000;
+    const HEAP_SAMPLE_CAPACITY: usize = 30;
+    const HEAP_SPARKLINE_WIDTH: f64 = 64.0;
+    const HEAP_SPARKLINE_HEIGHT: f64 = 16.0;
     const THEME_SWITCH_ANIMATION_MS: u32 = 320;
     const COMMITS_THIS_YEAR_FALLBACK: &str = "12";
     const COMMITS_CACHE_KEY_PREFIX: &str = "portfolio-commits-this-year-cache";
     const COMMITS_CACHE_MAX_AGE_MS: f64 = 24.0 * 60.0 * 60.0 * 1000.0;
     const GITHUB_ACCOUNT_LOGIN: &str = "kyler505";
+    const POSTS_INDEX_URL: &str = "/posts/index.json";
     const ENERGY_START_YEAR: i32 = 2026;
     const ENERGY_START_MONTH: u32 = 1;
     const ENERGY_START_DAY: u32 = 12;
@@ -68,11 +96,24 @@ mod frontend {
         label: &'static str,
     }
 
-    #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
-    struct SimpleDate {
-        year: i32,
-        month: u32,
-        day: u32,
+    #[derive(Clone, PartialEq)]
+    struct PostSummary {
+        slug: String,
+        title: String,
+        date: String,
+    }
+
+    #[derive(Clone, PartialEq)]
+    struct LoadedPost {
+        summary: PostSummary,
+        html: AttrValue,
+    }
+
+    #[derive(Clone, PartialEq)]
+    enum Loadable<T> {
+        Loading,
+        Ready(T),
+        Failed,
     }
 
     #[derive(Clone)]
@@ -312,7 +353,9 @@ mod frontend {
         Some(cache_entry.value.clone())
     }
 
-    async fn fetch_total_commits(url: &str) -> Result<u32, ()> {
+    // Returns the body along with the response's `Content-Type` (empty when
+    // the header is missing).
+    async fn fetch_text_with_type(url: &str, accept: &str) -> Result<(String, String), ()> {
         let Some(win) = window() else {
             return Err(());
         };
@@ -321,9 +364,7 @@ mod frontend {
         init.set_method("GET");
         init.set_mode(RequestMode::Cors);
         let request = Request::new_with_str_and_init(url, &init).map_err(|_| ())?;
-        let _ = request
-            .headers()
-            .set("Accept", "application/vnd.github+json");
+        let _ = request.headers().set("Accept", accept);
         let response_value = JsFuture::from(win.fetch_with_request(&request))
             .await
             .map_err(|_| ())?;
@@ -332,18 +373,93 @@ mod frontend {
             return Err(());
         }
 
+        let content_type = response
+            .headers()
+            .get("Content-Type")
+            .ok()
+            .flatten()
+            .unwrap_or_default();
         let text_promise = response
             .text()
             .map_err(|_| ())?;
-        let body_text = JsFuture::from(text_promise)
+        let body = JsFuture::from(text_promise)
             .await
             .map_err(|_| ())?
             .as_string()
             .ok_or(())?;
+        Ok((body, content_type))
+    }
+
+    async fn fetch_text(url: &str, accept: &str) -> Result<String, ()> {
+        fetch_text_with_type(url, accept).await.map(|(body, _)| body)
+    }
+
+    async fn fetch_total_commits(url: &str) -> Result<u32, ()> {
+        let body_text = fetch_text(url, "application/vnd.github+json").await?;
         let payload = JSON::parse(&body_text).map_err(|_| ())?;
         count_total_commits_from_payload(&payload).ok_or(())
     }
 
+    fn post_summary_from_value(value: &wasm_bindgen::JsValue) -> Option<PostSummary> {
+        let field = |name: &str| Reflect::get(value, &js_string(name)).ok()?.as_string();
+
+        let slug = field("slug")?;
+        if !is_valid_slug(&slug) {
+            return None;
+        }
+
+        // Posts sort by `date` as a string, so only strict ISO dates are kept.
+        let date = field("date")?;
+        parse_iso_date(&date)?;
+
+        Some(PostSummary {
+            slug,
+            title: field("title")?,
+            date,
+        })
+    }
+
+    fn parse_post_index(raw: &str) -> Option<Vec<PostSummary>> {
+        let payload = JSON::parse(raw).ok()?;
+        let entries = payload.dyn_into::<Array>().ok()?;
+        let mut posts = entries
+            .iter()
+            .filter_map(|entry| post_summary_from_value(&entry))
+            .collect::<Vec<_>>();
+        // Dates are ISO `YYYY-MM-DD`, so string order is date order.
+        posts.sort_by(|left, right| right.date.cmp(&left.date));
+        Some(posts)
+    }
+
+    async fn fetch_post_index() -> Result<Vec<PostSummary>, ()> {
+        let raw = fetch_text(POSTS_INDEX_URL, "application/json").await?;
+        parse_post_index(&raw).ok_or(())
+    }
+
+    async fn fetch_post(slug: &str) -> Result<Option<LoadedPost>, ()> {
+        if !is_valid_slug(slug) {
+            return Ok(None);
+        }
+
+        let index = fetch_post_index().await?;
+        let Some(summary) = index.into_iter().find(|post| post.slug == slug) else {
+            return Ok(None);
+        };
+
+        let (markdown, content_type) =
+            fetch_text_with_type(&post_markdown_url(slug), "text/markdown").await?;
+        // A missing `.md` file falls through to the SPA shell with status 200
+        // (Render rewrite, `trunk serve`), so HTML means the post isn't there.
+        if content_type.to_ascii_lowercase().starts_with("text/html") {
+            return Ok(None);
+        }
+
+        Ok(Some(LoadedPost {
+            summary,
+            html: AttrValue::from(render_markdown(&markdown)),
+        }))
+    }
+
     async fn fetch_commits_this_year(login: &str) -> Result<u32, ()> {
         let url = github_commit_search_url(login);
         fetch_total_commits(&url).await
@@ -513,58 +629,6 @@ mod frontend {
         Some(SimpleDate { year, month, day })
     }
 
-    fn is_leap_year(year: i32) -> bool {
-        (year % 4 == 0 && year % 100 != 0) || (year % 400 == 0)
-    }
-
-    fn days_in_month(year: i32, month: u32) -> u32 {
-        match month {
-            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
-            4 | 6 | 9 | 11 => 30,
-            2 if is_leap_year(year) => 29,
-            2 => 28,
-            _ => 30,
-        }
-    }
-
-    fn next_day(date: SimpleDate) -> SimpleDate {
-        let max_day = days_in_month(date.year, date.month);
-        if date.day < max_day {
-            return SimpleDate {
-                day: date.day + 1,
-                ..date
-            };
-        }
-
-        if date.month < 12 {
-            return SimpleDate {
-                year: date.year,
-                month: date.month + 1,
-                day: 1,
-            };
-        }
-
-        SimpleDate {
-            year: date.year + 1,
-            month: 1,
-            day: 1,
-        }
-    }
-
-    fn day_offset(start: SimpleDate, end: SimpleDate) -> Option<u32> {
-        if end < start {
-            return None;
-        }
-
-        let mut cursor = start;
-        let mut days: u32 = 0;
-        while cursor < end {
-            cursor = next_day(cursor);
-            days = days.checked_add(1)?;
-        }
-        Some(days)
-    }
-
     fn weekdays_since_energy_start() -> u32 {
         let start = SimpleDate {
             year: ENERGY_START_YEAR,
@@ -609,27 +673,67 @@ mod frontend {
         format!("{bytes} B")
     }
 
-    fn wasm_heap_size_value() -> String {
+    fn wasm_heap_size_bytes() -> Option<u64> {
         let memory = wasm_bindgen::memory()
             .dyn_into::<WebAssembly::Memory>()
-            .ok();
-        let Some(memory) = memory else {
-            return "heap unavailable".to_owned();
-        };
+            .ok()?;
+        let buffer = memory.buffer().dyn_into::<ArrayBuffer>().ok()?;
+        Some(buffer.byte_length() as u64)
+    }
+
+    fn wasm_heap_size_value() -> String {
+        wasm_heap_size_bytes()
+            .map(format_wasm_heap_size)
+            .unwrap_or_else(|| "heap unavailable".to_owned())
+    }
 
-        let buffer = memory.buffer().dyn_into::<ArrayBuffer>().ok();
-        let Some(buffer) = buffer else {
-            return "heap unavailable".to_owned();
+    fn record_heap_sample(samples: &mut VecDeque<u64>) {
+        let Some(bytes) = wasm_heap_size_bytes() else {
+            return;
         };
 
-        format_wasm_heap_size(buffer.byte_length() as u64)
+        if samples.len() >= HEAP_SAMPLE_CAPACITY {
+            samples.pop_front();
+        }
+        samples.push_back(bytes);
+    }
+
+    fn heap_sparkline_points(samples: &VecDeque<u64>) -> Option<String> {
+        if samples.len() < 2 {
+            return None;
+        }
+
+        let min = *samples.iter().min()?;
+        let max = *samples.iter().max()?;
+        let range = (max - min) as f64;
+        let step = HEAP_SPARKLINE_WIDTH / (samples.len() - 1) as f64;
+        // Keep a 1px inset so the stroke isn't clipped at the extremes.
+        let usable_height = HEAP_SPARKLINE_HEIGHT - 2.0;
+
+        let points = samples
+            .iter()
+            .enumerate()
+            .map(|(index, bytes)| {
+                let x = index as f64 * step;
+                let ratio = if range > 0.0 {
+                    (bytes - min) as f64 / range
+                } else {
+                    0.5
+                };
+                let y = 1.0 + usable_height * (1.0 - ratio);
+                format!("{x:.1},{y:.1}")
+            })
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        Some(points)
     }
 
     fn current_metrics(commits_this_year: &AttrValue) -> [Metric; 4] {
         [
             Metric {
                 value: AttrValue::from(wasm_heap_size_value()),
-                label: "wasm heap size",
+                label: HEAP_METRIC_LABEL,
             },
             Metric {
                 value: AttrValue::from(formatted_college_station_time()),
@@ -881,15 +985,508 @@ mod frontend {
         }
     }
 
-    #[function_component(App)]
-    fn app() -> Html {
-        let theme = use_state(resolve_theme);
-        let theme_icon_cycle = use_state(|| 0u32);
+    #[function_component(MetricSection)]
+    fn metric_section() -> Html {
         let commits_this_year = use_state(|| AttrValue::from(COMMITS_THIS_YEAR_FALLBACK));
         let active_metric = use_state(|| {
             current_metrics(&AttrValue::from(COMMITS_THIS_YEAR_FALLBACK))[0].clone()
         });
         let metric_cursor = use_mut_ref(|| 0usize);
+        let heap_samples = use_mut_ref(VecDeque::<u64>::new);
+
+        {
+            let commits_this_year = commits_this_year.clone();
+            use_effect_with((), move |_| {
+                spawn_local(async move {
+                    let value = resolve_commits_this_year(GITHUB_ACCOUNT_LOGIN).await;
+                    commits_this_year.set(AttrValue::from(value));
+                });
+
+                || ()
+            });
+        }
+
+        {
+            let active_metric = active_metric.clone();
+            let metric_cursor = metric_cursor.clone();
+            let commits_this_year = commits_this_year.clone();
+            use_effect_with((*commits_this_year).clone(), move |latest_commits| {
+                let metrics = current_metrics(latest_commits);
+                let current_index = {
+                    let cursor = metric_cursor.borrow();
+                    *cursor % metrics.len()
+                };
+
+                if current_index == 3 {
+                    active_metric.set(metrics[3].clone());
+                }
+
+                || ()
+            });
+        }
+
+        {
+            let active_metric = active_metric.clone();
+            let metric_cursor = metric_cursor.clone();
+            let commits_this_year = commits_this_year.clone();
+            use_effect_with((*commits_this_year).clone(), move |latest_commits| {
+                let mut interval_id = None;
+                let mut callback = None;
+                let latest_commits = latest_commits.clone();
+
+                if let Some(win) = window() {
+                    let tick = Closure::<dyn FnMut()>::new(move || {
+                        let metrics = current_metrics(&latest_commits);
+                        let len = metrics.len();
+                        if len == 0 {
+                            return;
+                        }
+
+                        let next_index = {
+                            let mut cursor = metric_cursor.borrow_mut();
+                            *cursor = (*cursor + 1) % len;
+                            *cursor
+                        };
+
+                        active_metric.set(metrics[next_index].clone());
+                    });
+
+                    interval_id = win
+                        .set_interval_with_callback_and_timeout_and_arguments_0(
+                            tick.as_ref().unchecked_ref(),
+                            METRIC_ROTATION_MS,
+                        )
+                        .ok();
+                    callback = Some(tick);
+                }
+
+                move || {
+                    if let (Some(win), Some(handle)) = (window(), interval_id) {
+                        win.clear_interval_with_handle(handle);
+                    }
+                    drop(callback);
+                }
+            });
+        }
+
+        {
+            let heap_samples = heap_samples.clone();
+            use_effect_with((), move |_| {
+                let mut interval_id = None;
+                let mut callback = None;
+                record_heap_sample(&mut heap_samples.borrow_mut());
+
+                if let Some(win) = window() {
+                    let sample = Closure::<dyn FnMut()>::new(move || {
+                        record_heap_sample(&mut heap_samples.borrow_mut());
+                    });
+
+                    interval_id = win
+                        .set_interval_with_callback_and_timeout_and_arguments_0(
+                            sample.as_ref().unchecked_ref(),
+                            HEAP_SAMPLE_INTERVAL_MS,
+                        )
+                        .ok();
+                    callback = Some(sample);
+                }
+
+                move || {
+                    if let (Some(win), Some(handle)) = (window(), interval_id) {
+                        win.clear_interval_with_handle(handle);
+                    }
+                    drop(callback);
+                }
+            });
+        }
+
+        let metric_key = format!("{}::{}", active_metric.value, active_metric.label);
+        let heap_sparkline = if active_metric.label == HEAP_METRIC_LABEL {
+            heap_sparkline_points(&heap_samples.borrow())
+        } else {
+            None
+        };
+        let sparkline_view_box = format!("0 0 {HEAP_SPARKLINE_WIDTH} {HEAP_SPARKLINE_HEIGHT}");
+
+        html! {
+            <section aria-labelledby="now-heading" class="section-block now-metric">
+                <h2 id="now-heading">{"Metric"}</h2>
+                <div class="metric-cycle">
+                    <div class="metric-entry" key={metric_key.clone()}>
+                        <p class="metric-value">{active_metric.value.clone()}</p>
+                        <p class="metric-label">{active_metric.label}</p>
+                        if let Some(points) = heap_sparkline {
+                            <svg
+                                class="metric-sparkline"
+                                viewBox={sparkline_view_box}
+                                preserveAspectRatio="none"
+                                aria-hidden="true"
+                                focusable="false"
+                            >
+                                <polyline points={points} />
+                            </svg>
+                        }
+                    </div>
+                </div>
+            </section>
+        }
+    }
+
+    #[derive(Clone, PartialEq)]
+    struct PreviewHandlers {
+        on_pointer_preview: Callback<(PreviewAsset, i32, i32)>,
+        on_focus_preview: Callback<PreviewAsset>,
+        on_hide_preview: Callback<()>,
+    }
+
+    #[derive(Properties, PartialEq)]
+    struct PageProps {
+        previews: PreviewHandlers,
+    }
+
+    #[function_component(HomePage)]
+    fn home_page(props: &PageProps) -> Html {
+        html! {
+            <>
+                <section aria-labelledby="about-heading" class="section-block">
+                    <h2 id="about-heading">{"About"}</h2>
+                    <p>
+                        {"Computer Science student at Texas A&M building dependable software for campus operations at "}
+                        <ExternalLink
+                            href="https://www.it.tamu.edu/services/services-by-category/desktop-and-mobile-computing/techhub.html"
+                            label="TechHub"
+                            extra_class={classes!("techhub-link")}
+                            preview={PreviewAsset {
+                                src: AttrValue::from("/previews/manual/techhub.png"),
+                                alt: AttrValue::from("TechHub website screenshot"),
+                            }}
+                            on_pointer_preview={props.previews.on_pointer_preview.clone()}
+                            on_focus_preview={props.previews.on_focus_preview.clone()}
+                            on_hide_preview={props.previews.on_hide_preview.clone()}
+                        />
+                        {" and practical machine learning projects."}
+                    </p>
+                </section>
+
+                <section aria-labelledby="links-heading" class="section-block">
+                    <h2 id="links-heading">{"Links"}</h2>
+                    <ul class="row-list">
+                        <li>
+                            <ExternalLink
+                                href="https://github.com/kyler505"
+                                label="GitHub"
+                                preview={PreviewAsset {
+                                    src: AttrValue::from(GITHUB_LINK_SCREENSHOT),
+                                    alt: AttrValue::from("Screenshot of the kyler505 GitHub profile page"),
+                                }}
+                                on_pointer_preview={props.previews.on_pointer_preview.clone()}
+                                on_focus_preview={props.previews.on_focus_preview.clone()}
+                                on_hide_preview={props.previews.on_hide_preview.clone()}
+                            />
+                            <span class="muted">{" — code and experiments"}</span>
+                        </li>
+                        <li>
+                            <ExternalLink
+                                href="https://www.linkedin.com/in/kylercao"
+                                label="LinkedIn"
+                                preview={PreviewAsset {
+                                    src: AttrValue::from("/previews/manual/linkedin.png"),
+                                    alt: AttrValue::from("LinkedIn profile screenshot"),
+                                }}
+                                on_pointer_preview={props.previews.on_pointer_preview.clone()}
+                                on_focus_preview={props.previews.on_focus_preview.clone()}
+                                on_hide_preview={props.previews.on_hide_preview.clone()}
+                            />
+                            <span class="muted">{" — professional profile"}</span>
+                        </li>
+                        <li>
+                            <ExternalLink
+                                href="/resume.pdf"
+                                label="Resume"
+                                on_pointer_preview={props.previews.on_pointer_preview.clone()}
+                                on_focus_preview={props.previews.on_focus_preview.clone()}
+                                on_hide_preview={props.previews.on_hide_preview.clone()}
+                            />
+                            <span class="muted">{" — updated feb 5 26"}</span>
+                        </li>
+                    </ul>
+                </section>
+
+                <section aria-labelledby="languages-heading" class="section-block">
+                    <h2 id="languages-heading">{"Languages"}</h2>
+                    <ul class="inline-list">
+                        <li><span class="muted">{"Primary"}</span>{"Java, Python, C++, JavaScript, TypeScript"}</li>
+                        <li><span class="muted">{"Database"}</span>{"SQL (PostgreSQL, MySQL)"}</li>
+                        <li><span class="muted">{"Also"}</span>{"C#, HTML, CSS"}</li>
+                    </ul>
+                </section>
+
+                <MetricSection />
+            </>
+        }
+    }
+
+    fn project_row(project: &Project, previews: &PreviewHandlers) -> Html {
+        html! {
+            <li key={project.href}>
+                <ExternalLink
+                    href={project.href}
+                    label={project.name}
+                    preview={PreviewAsset {
+                        src: AttrValue::from(project.preview_src),
+                        alt: AttrValue::from(project.preview_alt),
+                    }}
+                    on_pointer_preview={previews.on_pointer_preview.clone()}
+                    on_focus_preview={previews.on_focus_preview.clone()}
+                    on_hide_preview={previews.on_hide_preview.clone()}
+                />
+                <span class="muted">{format!(" — {}", project.summary)}</span>
+            </li>
+        }
+    }
+
+    #[function_component(ProjectsPage)]
+    fn projects_page(props: &PageProps) -> Html {
+        html! {
+            <section aria-labelledby="projects-heading" class="section-block">
+                <h2 id="projects-heading">{"Projects"}</h2>
+                <ul class="row-list">
+                    { for projects().iter().map(|project| project_row(project, &props.previews)) }
+                </ul>
+            </section>
+        }
+    }
+
+    #[function_component(BlogPage)]
+    fn blog_page() -> Html {
+        let posts = use_state(|| Loadable::<Vec<PostSummary>>::Loading);
+
+        {
+            let posts = posts.clone();
+            use_effect_with((), move |_| {
+                spawn_local(async move {
+                    let next = match fetch_post_index().await {
+                        Ok(list) => Loadable::Ready(list),
+                        Err(()) => Loadable::Failed,
+                    };
+                    posts.set(next);
+                });
+
+                || ()
+            });
+        }
+
+        let body = match &*posts {
+            Loadable::Loading => html! { <p class="muted">{"Loading posts…"}</p> },
+            Loadable::Failed => html! { <p class="muted">{"Posts couldn't be loaded."}</p> },
+            Loadable::Ready(list) if list.is_empty() => html! { <p class="muted">{"No posts yet."}</p> },
+            Loadable::Ready(list) => html! {
+                <ul class="row-list">
+                    { for list.iter().map(|post| html! {
+                        <li key={post.slug.clone()}>
+                            <Link<Route> classes={classes!("link")} to={Route::BlogPost { slug: post.slug.clone() }}>
+                                {post.title.clone()}
+                            </Link<Route>>
+                            <span class="muted">{format!(" — {}", post.date)}</span>
+                        </li>
+                    }) }
+                </ul>
+            },
+        };
+
+        html! {
+            <section aria-labelledby="blog-heading" class="section-block">
+                <h2 id="blog-heading">{"Blog"}</h2>
+                {body}
+            </section>
+        }
+    }
+
+    #[derive(Properties, PartialEq)]
+    struct BlogPostProps {
+        slug: AttrValue,
+    }
+
+    #[function_component(BlogPostPage)]
+    fn blog_post_page(props: &BlogPostProps) -> Html {
+        let post = use_state(|| Loadable::<Option<LoadedPost>>::Loading);
+        let request_generation = use_mut_ref(|| 0u32);
+
+        {
+            let post = post.clone();
+            use_effect_with(props.slug.clone(), move |slug| {
+                post.set(Loadable::Loading);
+                // Navigating between posts reuses this component; only the
+                // latest slug's response may update it.
+                let generation = {
+                    let mut current = request_generation.borrow_mut();
+                    *current = current.wrapping_add(1);
+                    *current
+                };
+                let slug = slug.clone();
+                spawn_local(async move {
+                    let next = match fetch_post(&slug).await {
+                        Ok(loaded) => Loadable::Ready(loaded),
+                        Err(()) => Loadable::Failed,
+                    };
+                    if *request_generation.borrow() == generation {
+                        post.set(next);
+                    }
+                });
+
+                || ()
+            });
+        }
+
+        let back_link = html! {
+            <p class="post-back">
+                <Link<Route> classes={classes!("link")} to={Route::Blog}>{"All posts"}</Link<Route>>
+            </p>
+        };
+
+        match &*post {
+            Loadable::Ready(Some(loaded)) => html! {
+                <article aria-labelledby="post-heading" class="section-block">
+                    <h2 id="post-heading">{loaded.summary.title.clone()}</h2>
+                    <p class="muted post-date">{loaded.summary.date.clone()}</p>
+                    <div class="post-body">{Html::from_html_unchecked(loaded.html.clone())}</div>
+                    {back_link}
+                </article>
+            },
+            state => {
+                let message = match state {
+                    Loadable::Loading => "Loading post…",
+                    Loadable::Failed => "This post couldn't be loaded.",
+                    _ => "Post not found.",
+                };
+                html! {
+                    <section aria-labelledby="post-heading" class="section-block">
+                        <h2 id="post-heading">{"Blog"}</h2>
+                        <p class="muted">{message}</p>
+                        {back_link}
+                    </section>
+                }
+            }
+        }
+    }
+
+    #[function_component(ResumePage)]
+    fn resume_page(props: &PageProps) -> Html {
+        html! {
+            <section aria-labelledby="resume-heading" class="section-block">
+                <h2 id="resume-heading">{"Resume"}</h2>
+                <p>
+                    <ExternalLink
+                        href="/resume.pdf"
+                        label="Open the PDF"
+                        on_pointer_preview={props.previews.on_pointer_preview.clone()}
+                        on_focus_preview={props.previews.on_focus_preview.clone()}
+                        on_hide_preview={props.previews.on_hide_preview.clone()}
+                    />
+                    <span class="muted">{" — updated feb 5 26"}</span>
+                </p>
+            </section>
+        }
+    }
+
+    #[function_component(NotFoundPage)]
+    fn not_found_page() -> Html {
+        html! {
+            <section aria-labelledby="not-found-heading" class="section-block">
+                <h2 id="not-found-heading">{"Not found"}</h2>
+                <p>
+                    {"Nothing lives at this address. "}
+                    <NavLink to={Route::Home} label="Back home" />
+                </p>
+            </section>
+        }
+    }
+
+    fn switch_route(route: Route, previews: &PreviewHandlers) -> Html {
+        let previews = previews.clone();
+        match route {
+            Route::Home => html! { <HomePage {previews} /> },
+            Route::Projects => html! { <ProjectsPage {previews} /> },
+            Route::Blog => html! { <BlogPage /> },
+            Route::BlogPost { slug } => html! { <BlogPostPage {slug} /> },
+            Route::Resume => html! { <ResumePage {previews} /> },
+            Route::NotFound => html! { <NotFoundPage /> },
+        }
+    }
+
+    #[derive(Properties, PartialEq)]
+    struct NavLinkProps {
+        to: Route,
+        label: AttrValue,
+    }
+
+    #[function_component(NavLink)]
+    fn nav_link(props: &NavLinkProps) -> Html {
+        let navigator = use_navigator();
+        let current_route = use_route::<Route>();
+        let is_current = current_route
+            .map(|route| route.nav_section() == props.to)
+            .unwrap_or(false);
+
+        let onclick = {
+            let to = props.to.clone();
+            Callback::from(move |event: MouseEvent| {
+                // Leave modified clicks (new tab/window) to the browser.
+                if event.button() != 0
+                    || event.ctrl_key()
+                    || event.meta_key()
+                    || event.shift_key()
+                    || event.alt_key()
+                {
+                    return;
+                }
+
+                let Some(navigator) = navigator.as_ref() else {
+                    return;
+                };
+                event.prevent_default();
+                navigator.push(&to);
+            })
+        };
+
+        html! {
+            <a
+                class="link"
+                href={props.to.to_path()}
+                aria-current={is_current.then_some("page")}
+                {onclick}
+            >
+                {props.label.clone()}
+            </a>
+        }
+    }
+
+    #[derive(Properties, PartialEq)]
+    struct RouteChangeWatcherProps {
+        on_route_change: Callback<()>,
+        children: Children,
+    }
+
+    // Back/forward removes the hovered or focused link without firing
+    // `mouseleave`/`blur`, so the shell hides the card whenever the path changes.
+    #[function_component(RouteChangeWatcher)]
+    fn route_change_watcher(props: &RouteChangeWatcherProps) -> Html {
+        let path = use_location().map(|location| location.path().to_string());
+
+        {
+            let on_route_change = props.on_route_change.clone();
+            use_effect_with(path, move |_| {
+                on_route_change.emit(());
+                || ()
+            });
+        }
+
+        html! { <>{ props.children.clone() }</> }
+    }
+
+    #[function_component(App)]
+    fn app() -> Html {
+        let theme = use_state(resolve_theme);
+        let theme_icon_cycle = use_state(|| 0u32);
         let theme_animation_timeout = use_mut_ref(|| Option::<Timeout>::None);
         let preview_card = use_state(PreviewCardState::hidden);
         let preview_anchor = use_state(|| Option::<PreviewAnchor>::None);
@@ -901,6 +1498,8 @@ mod frontend {
         let loaded_preview_urls = use_mut_ref(|| HashSet::<String>::new());
         let preload_images = use_mut_ref(Vec::<HtmlImageElement>::new);
         let active_preview_target = use_state(|| Option::<PreviewAsset>::None);
+        let dismissed_preview = use_mut_ref(|| Option::<PreviewAsset>::None);
+        let latest_dismiss_preview = use_mut_ref(|| Option::<Callback<()>>::None);
 
         {
             let loaded_preview_urls = loaded_preview_urls.clone();
@@ -977,81 +1576,6 @@ mod frontend {
             })
         };
 
-        {
-            let commits_this_year = commits_this_year.clone();
-            use_effect_with((), move |_| {
-                spawn_local(async move {
-                    let value = resolve_commits_this_year(GITHUB_ACCOUNT_LOGIN).await;
-                    commits_this_year.set(AttrValue::from(value));
-                });
-
-                || ()
-            });
-        }
-
-        {
-            let active_metric = active_metric.clone();
-            let metric_cursor = metric_cursor.clone();
-            let commits_this_year = commits_this_year.clone();
-            use_effect_with((*commits_this_year).clone(), move |latest_commits| {
-                let metrics = current_metrics(latest_commits);
-                let current_index = {
-                    let cursor = metric_cursor.borrow();
-                    *cursor % metrics.len()
-                };
-
-                if current_index == 3 {
-                    active_metric.set(metrics[3].clone());
-                }
-
-                || ()
-            });
-        }
-
-        {
-            let active_metric = active_metric.clone();
-            let metric_cursor = metric_cursor.clone();
-            let commits_this_year = commits_this_year.clone();
-            use_effect_with((*commits_this_year).clone(), move |latest_commits| {
-                let mut interval_id = None;
-                let mut callback = None;
-                let latest_commits = latest_commits.clone();
-
-                if let Some(win) = window() {
-                    let tick = Closure::<dyn FnMut()>::new(move || {
-                        let metrics = current_metrics(&latest_commits);
-                        let len = metrics.len();
-                        if len == 0 {
-                            return;
-                        }
-
-                        let next_index = {
-                            let mut cursor = metric_cursor.borrow_mut();
-                            *cursor = (*cursor + 1) % len;
-                            *cursor
-                        };
-
-                        active_metric.set(metrics[next_index].clone());
-                    });
-
-                    interval_id = win
-                        .set_interval_with_callback_and_timeout_and_arguments_0(
-                            tick.as_ref().unchecked_ref(),
-                            METRIC_ROTATION_MS,
-                        )
-                        .ok();
-                    callback = Some(tick);
-                }
-
-                move || {
-                    if let (Some(win), Some(handle)) = (window(), interval_id) {
-                        win.clear_interval_with_handle(handle);
-                    }
-                    drop(callback);
-                }
-            });
-        }
-
         let on_pointer_preview = {
             let preview_card = preview_card.clone();
             let preview_anchor = preview_anchor.clone();
@@ -1061,8 +1585,13 @@ mod frontend {
             let pointer_raf_closure = pointer_raf_closure.clone();
             let active_preview_target = active_preview_target.clone();
             let loaded_preview_urls = loaded_preview_urls.clone();
+            let dismissed_preview = dismissed_preview.clone();
             Callback::from(
                 move |(asset, client_x, client_y): (PreviewAsset, i32, i32)| {
+                    if dismissed_preview.borrow().as_ref() == Some(&asset) {
+                        return;
+                    }
+
                     *pending_pointer_preview.borrow_mut() = Some(PendingPointerPreview {
                         asset,
                         client_x,
@@ -1159,7 +1688,9 @@ mod frontend {
             let preview_size = preview_size.clone();
             let active_preview_target = active_preview_target.clone();
             let loaded_preview_urls = loaded_preview_urls.clone();
+            let dismissed_preview = dismissed_preview.clone();
             Callback::from(move |asset: PreviewAsset| {
+                *dismissed_preview.borrow_mut() = None;
                 active_preview_target.set(Some(asset.clone()));
                 let anchor = PreviewAnchor::Focus;
                 preview_anchor.set(Some(anchor));
@@ -1180,12 +1711,14 @@ mod frontend {
             let pointer_raf_handle = pointer_raf_handle.clone();
             let pointer_raf_closure = pointer_raf_closure.clone();
             let active_preview_target = active_preview_target.clone();
+            let dismissed_preview = dismissed_preview.clone();
             Callback::from(move |_| {
                 clear_pending_pointer_preview(
                     &pending_pointer_preview,
                     &pointer_raf_handle,
                     &pointer_raf_closure,
                 );
+                *dismissed_preview.borrow_mut() = None;
                 active_preview_target.set(None);
                 preview_anchor.set(None);
                 let mut next = (*preview_card).clone();
@@ -1194,6 +1727,23 @@ mod frontend {
             })
         };
 
+        // Escape hides the card without moving pointer or focus. Pointer
+        // moves over the same link keep it hidden until the link is left;
+        // other links still preview normally.
+        let on_dismiss_preview = {
+            let on_hide_preview = on_hide_preview.clone();
+            let active_preview_target = active_preview_target.clone();
+            let dismissed_preview = dismissed_preview.clone();
+            Callback::from(move |_| {
+                let target = (*active_preview_target).clone();
+                on_hide_preview.emit(());
+                *dismissed_preview.borrow_mut() = target;
+            })
+        };
+        // The keydown listener outlives renders, so it reads the latest
+        // dismiss callback instead of the one from when it was registered.
+        *latest_dismiss_preview.borrow_mut() = Some(on_dismiss_preview);
+
         let reclamp_preview = {
             let preview_anchor = preview_anchor.clone();
             let preview_card = preview_card.clone();
@@ -1259,6 +1809,35 @@ mod frontend {
             });
         }
 
+        {
+            let latest_dismiss_preview = latest_dismiss_preview.clone();
+            use_effect_with(preview_card.visible, move |visible| {
+                let win = window().filter(|_| *visible);
+                let keydown_handler =
+                    Closure::<dyn FnMut(KeyboardEvent)>::new(move |event: KeyboardEvent| {
+                        if event.key() != "Escape" {
+                            return;
+                        }
+
+                        let on_dismiss_preview = latest_dismiss_preview.borrow().clone();
+                        if let Some(on_dismiss_preview) = on_dismiss_preview {
+                            on_dismiss_preview.emit(());
+                        }
+                    });
+
+                if let Some(win) = win.as_ref() {
+                    win.set_onkeydown(Some(keydown_handler.as_ref().unchecked_ref()));
+                }
+
+                move || {
+                    if let Some(win) = win {
+                        win.set_onkeydown(None);
+                    }
+                    drop(keydown_handler);
+                }
+            });
+        }
+
         let on_preview_media_loaded = {
             let reclamp_preview = reclamp_preview.clone();
             Callback::from(move |_| {
@@ -1271,160 +1850,45 @@ mod frontend {
             preview_card.x, preview_card.y
         );
         let theme_icon_key = format!("theme-icon-{}", *theme_icon_cycle);
-        let metric_key = format!("{}::{}", active_metric.value, active_metric.label);
+        let render_route = {
+            let previews = PreviewHandlers {
+                on_pointer_preview,
+                on_focus_preview,
+                on_hide_preview: on_hide_preview.clone(),
+            };
+            move |route: Route| switch_route(route, &previews)
+        };
 
         html! {
-            <>
+            <BrowserRouter>
                 <a class="skip-link" href="#content">{"Skip to main content"}</a>
                 <div class="page-shell">
                     <header class="site-header" aria-labelledby="identity-heading">
                         <h1 id="identity-heading">{"Kyler Cao"}</h1>
-                        <button
-                            class="theme-toggle"
-                            type="button"
-                            aria-label={(*theme).toggle_label()}
-                            aria-pressed={(*theme).pressed().to_string()}
-                            onclick={on_toggle}
-                        >
-                            <span key={theme_icon_key} class="theme-toggle-icon" aria-hidden="true">{theme_toggle_icon(*theme)}</span>
-                        </button>
+                        <div class="site-header-actions">
+                            <nav class="site-nav" aria-label="Primary">
+                                <ul class="site-nav-list">
+                                    { for PRIMARY_NAV.iter().map(|(route, label)| html! {
+                                        <li key={*label}><NavLink to={route.clone()} label={*label} /></li>
+                                    }) }
+                                </ul>
+                            </nav>
+                            <button
+                                class="theme-toggle"
+                                type="button"
+                                aria-label={(*theme).toggle_label()}
+                                aria-pressed={(*theme).pressed().to_string()}
+                                onclick={on_toggle}
+                            >
+                                <span key={theme_icon_key} class="theme-toggle-icon" aria-hidden="true">{theme_toggle_icon(*theme)}</span>
+                            </button>
+                        </div>
                     </header>
 
                     <main id="content">
-                        <section aria-labelledby="about-heading" class="section-block">
-                            <h2 id="about-heading">{"About"}</h2>
-                            <p>
-                                {"Computer Science student at Texas A&M building dependable software for campus operations at "}
-                                <ExternalLink
-                                    href="https://www.it.tamu.edu/services/services-by-category/desktop-and-mobile-computing/techhub.html"
-                                    label="TechHub"
-                                    extra_class={classes!("techhub-link")}
-                                    preview={PreviewAsset {
-                                        src: AttrValue::from("/previews/manual/techhub.png"),
-                                        alt: AttrValue::from("TechHub website screenshot"),
-                                    }}
-                                    on_pointer_preview={on_pointer_preview.clone()}
-                                    on_focus_preview={on_focus_preview.clone()}
-                                    on_hide_preview={on_hide_preview.clone()}
-                                />
-                                {" and practical machine learning projects."}
-                            </p>
-                        </section>
-
-                        <section aria-labelledby="apps-heading" class="section-block">
-                            <h2 id="apps-heading">{"Apps"}</h2>
-
-                            <div class="app-group">
-                                <h3>{"Builds"}</h3>
-                                <ul class="row-list">
-                                    <li>
-                                        <ExternalLink
-                                            href="https://github.com/NujhatJalil/SHADE-project"
-                                            label="Project SHADE"
-                                            preview={PreviewAsset {
-                                                src: AttrValue::from("/previews/og/project-shade-og.png"),
-                                                alt: AttrValue::from("GitHub Open Graph image for Project SHADE repository"),
-                                            }}
-                                            on_pointer_preview={on_pointer_preview.clone()}
-                                            on_focus_preview={on_focus_preview.clone()}
-                                            on_hide_preview={on_hide_preview.clone()}
-                                        />
-                                        <span class="muted">{" — lstm team for ensemble heat-wave forecasting model"}</span>
-                                    </li>
-                                    <li>
-                                        <ExternalLink
-                                            href="https://github.com/kyler505/temp-data-pipeline"
-                                            label="Temp Data Pipeline"
-                                            preview={PreviewAsset {
-                                                src: AttrValue::from("/previews/og/temp-data-pipeline-og.png"),
-                                                alt: AttrValue::from("GitHub Open Graph image for Temp Data Pipeline repository"),
-                                            }}
-                                            on_pointer_preview={on_pointer_preview.clone()}
-                                            on_focus_preview={on_focus_preview.clone()}
-                                            on_hide_preview={on_hide_preview.clone()}
-                                        />
-                                        <span class="muted">{" — data pipelines for daily temp max prediction"}</span>
-                                    </li>
-                                    <li>
-                                        <ExternalLink
-                                            href="https://github.com/kyler505/techhub-dns"
-                                            label="TechHub Delivery Platform"
-                                            preview={PreviewAsset {
-                                                src: AttrValue::from("/previews/og/techhub-delivery-platform-og.png"),
-                                                alt: AttrValue::from("GitHub Open Graph image for TechHub Delivery Platform repository"),
-                                            }}
-                                            on_pointer_preview={on_pointer_preview.clone()}
-                                            on_focus_preview={on_focus_preview.clone()}
-                                            on_hide_preview={on_hide_preview.clone()}
-                                        />
-                                        <span class="muted">{" — internal tool built from the ground up with react + flask"}</span>
-                                    </li>
-                                </ul>
-                            </div>
-
-                            <div class="app-group">
-                                <h3>{"Links"}</h3>
-                                <ul class="row-list">
-                                    <li>
-                                        <ExternalLink
-                                            href="https://github.com/kyler505"
-                                            label="GitHub"
-                                            preview={PreviewAsset {
-                                                src: AttrValue::from(GITHUB_LINK_SCREENSHOT),
-                                                alt: AttrValue::from("Screenshot of the kyler505 GitHub profile page"),
-                                            }}
-                                            on_pointer_preview={on_pointer_preview.clone()}
-                                            on_focus_preview={on_focus_preview.clone()}
-                                            on_hide_preview={on_hide_preview.clone()}
-                                        />
-                                        <span class="muted">{" — code and experiments"}</span>
-                                    </li>
-                                    <li>
-                                        <ExternalLink
-                                            href="https://www.linkedin.com/in/kylercao"
-                                            label="LinkedIn"
-                                            preview={PreviewAsset {
-                                                src: AttrValue::from("/previews/manual/linkedin.png"),
-                                                alt: AttrValue::from("LinkedIn profile screenshot"),
-                                            }}
-                                            on_pointer_preview={on_pointer_preview.clone()}
-                                            on_focus_preview={on_focus_preview.clone()}
-                                            on_hide_preview={on_hide_preview.clone()}
-                                        />
-                                        <span class="muted">{" — professional profile"}</span>
-                                    </li>
-                                    <li>
-                                        <ExternalLink
-                                            href="/resume.pdf"
-                                            label="Resume"
-                                            on_pointer_preview={on_pointer_preview.clone()}
-                                            on_focus_preview={on_focus_preview.clone()}
-                                            on_hide_preview={on_hide_preview.clone()}
-                                        />
-                                        <span class="muted">{" — updated feb 5 26"}</span>
-                                    </li>
-                                </ul>
-                            </div>
-                        </section>
-
-                        <section aria-labelledby="languages-heading" class="section-block">
-                            <h2 id="languages-heading">{"Languages"}</h2>
-                            <ul class="inline-list">
-                                <li><span class="muted">{"Primary"}</span>{"Java, Python, C++, JavaScript, TypeScript"}</li>
-                                <li><span class="muted">{"Database"}</span>{"SQL (PostgreSQL, MySQL)"}</li>
-                                <li><span class="muted">{"Also"}</span>{"C#, HTML, CSS"}</li>
-                            </ul>
-                        </section>
-
-                        <section aria-labelledby="now-heading" class="section-block now-metric">
-                            <h2 id="now-heading">{"Metric"}</h2>
-                            <div class="metric-cycle">
-                                <div class="metric-entry" key={metric_key.clone()}>
-                                    <p class="metric-value">{active_metric.value.clone()}</p>
-                                    <p class="metric-label">{active_metric.label}</p>
-                                </div>
-                            </div>
-                        </section>
+                        <RouteChangeWatcher on_route_change={on_hide_preview}>
+                            <Switch<Route> render={render_route} />
+                        </RouteChangeWatcher>
                     </main>
                 </div>
                 <aside
@@ -1441,7 +1905,7 @@ mod frontend {
                         onerror={on_preview_media_loaded}
                     />
                 </aside>
-            </>
+            </BrowserRouter>
         }
     }
 
@@ -0,0 +1,61 @@
+pub struct Project {
+    pub name: &'static str,
+    pub href: &'static str,
+    pub summary: &'static str,
+    pub preview_src: &'static str,
+    pub preview_alt: &'static str,
+}
+
+const PROJECTS: [Project; 3] = [
+    Project {
+        name: "Project SHADE",
+        href: "https://github.com/NujhatJalil/SHADE-project",
+        summary: "lstm team for ensemble heat-wave forecasting model",
+        preview_src: "/previews/og/project-shade-og.png",
+        preview_alt: "GitHub Open Graph image for Project SHADE repository",
+    },
+    Project {
+        name: "Temp Data Pipeline",
+        href: "https://github.com/kyler505/temp-data-pipeline",
+        summary: "data pipelines for daily temp max prediction",
+        preview_src: "/previews/og/temp-data-pipeline-og.png",
+        preview_alt: "GitHub Open Graph image for Temp Data Pipeline repository",
+    },
+    Project {
+        name: "TechHub Delivery Platform",
+        href: "https://github.com/kyler505/techhub-dns",
+        summary: "internal tool built from the ground up with react + flask",
+        preview_src: "/previews/og/techhub-delivery-platform-og.png",
+        preview_alt: "GitHub Open Graph image for TechHub Delivery Platform repository",
+    },
+];
+
+pub fn projects() -> &'static [Project] {
+    &PROJECTS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    #[test]
+    fn preview_assets_exist() {
+        for project in projects() {
+            let relative = project.preview_src.trim_start_matches('/');
+            let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(relative);
+            assert!(path.is_file(), "{} preview missing at {}", project.name, path.display());
+        }
+    }
+
+    #[test]
+    fn names_and_links_are_unique() {
+        let list = projects();
+        for (index, project) in list.iter().enumerate() {
+            for other in &list[index + 1..] {
+                assert_ne!(project.name, other.name);
+                assert_ne!(project.href, other.href);
+            }
+        }
+    }
+}
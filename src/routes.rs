@@ -0,0 +1,36 @@
+use yew_router::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, Routable)]
+pub enum Route {
+    #[at("/")]
+    Home,
+    #[at("/projects")]
+    Projects,
+    #[at("/blog")]
+    Blog,
+    #[at("/blog/:slug")]
+    BlogPost { slug: String },
+    #[at("/resume")]
+    Resume,
+    #[not_found]
+    #[at("/404")]
+    NotFound,
+}
+
+impl Route {
+    /// The primary nav entry this route belongs to, so nested pages like a
+    /// blog post still mark their section as current.
+    pub fn nav_section(&self) -> Route {
+        match self {
+            Route::BlogPost { .. } => Route::Blog,
+            other => other.clone(),
+        }
+    }
+}
+
+pub const PRIMARY_NAV: [(Route, &str); 4] = [
+    (Route::Home, "Home"),
+    (Route::Projects, "Projects"),
+    (Route::Blog, "Blog"),
+    (Route::Resume, "Resume"),
+];